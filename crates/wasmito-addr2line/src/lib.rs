@@ -18,6 +18,16 @@ pub struct Mapping {
     pub address: u64,
     pub range_size: u64,
     pub location: Location,
+    /// The WASM function this address range belongs to, if it could be
+    /// attributed to one.
+    pub function: Option<FunctionSymbol>,
+}
+
+/// A WASM function's index and, when the `name` custom section carries one, its name.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct FunctionSymbol {
+    pub index: u32,
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -31,6 +41,7 @@ pub struct MappingWithInstructions {
     pub address_range: Range<u64>,
     pub instructions: Vec<PositionedInstruction>,
     pub location: Location,
+    pub function: Option<FunctionSymbol>,
 }
 
 impl MappingWithInstructions {
@@ -39,11 +50,13 @@ impl MappingWithInstructions {
             address,
             range_size,
             location,
+            function,
         } = mapping;
         Self {
             address_range: address..(address + range_size),
             instructions: vec![],
             location,
+            function,
         }
     }
 }
@@ -60,6 +73,38 @@ pub struct Location {
     pub column: Option<u32>,
 }
 
+/// Rewrites the leading segments of a path, longest-prefix-first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PathRemapper {
+    rules: Vec<(String, String)>,
+}
+
+impl PathRemapper {
+    /// Builds a remapper from an ordered list of `(from_prefix, to_prefix)` pairs.
+    #[must_use]
+    pub fn new(rules: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut rules: Vec<(String, String)> = rules.into_iter().collect();
+        rules.sort_by_key(|(a, _)| std::cmp::Reverse(a.len()));
+        Self { rules }
+    }
+
+    /// Rewrites `path` using the longest matching rule, if any.
+    #[must_use]
+    pub fn remap(&self, path: &str) -> String {
+        self.rules
+            .iter()
+            .find_map(|(from, to)| {
+                path.strip_prefix(from.as_str())
+                    // Only match whole leading segments, so `/src` does not rewrite `/srcx/a.c`.
+                    .filter(|rest| {
+                        rest.is_empty() || rest.starts_with(['/', '\\']) || from.ends_with(['/', '\\'])
+                    })
+                    .map(|rest| format!("{to}{rest}"))
+            })
+            .unwrap_or_else(|| path.to_string())
+    }
+}
+
 impl From<Addr2LineLocation<'_>> for Location {
     fn from(value: Addr2LineLocation<'_>) -> Self {
         Self {
@@ -70,6 +115,23 @@ impl From<Addr2LineLocation<'_>> for Location {
     }
 }
 
+/// One entry of an inline call chain.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Frame {
+    pub location: Location,
+    /// The demangled function name, when DWARF carries one.
+    pub function: Option<String>,
+}
+
+/// A [`Mapping`] with its full inline call chain, innermost first.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MappingWithFrames {
+    pub address: u64,
+    pub range_size: u64,
+    pub frames: Vec<Frame>,
+    pub function: Option<FunctionSymbol>,
+}
+
 /// Macro to append the current file, line and column to a `&'static str`
 /// Example: "src/lib.rs:167:58"
 macro_rules! location {
@@ -88,21 +150,57 @@ pub(crate) struct CodeSectionInformation {
     pub(crate) size: u32,
 }
 
+/// A defined WASM function's byte range within the module, together with
+/// the [`FunctionSymbol`] attributed to it.
+struct FunctionBody {
+    range: Range<u64>,
+    symbol: FunctionSymbol,
+}
+
+/// Sentinel `set_address` values link-time dead-code elimination leaves
+/// behind in the DWARF line program for garbage-collected functions.
+const TOMBSTONE_SENTINELS: [u64; 3] = [0, u32::MAX as u64, (u32::MAX - 1) as u64];
+
+/// Whether `address` (relative to the start of the code section) is a
+/// tombstoned line-table row: either a known sentinel, or simply outside
+/// the code section's bounds.
+fn is_tombstone_address(address: u64, code_section_size: u64) -> bool {
+    TOMBSTONE_SENTINELS.contains(&address) || address >= code_section_size
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Module(Vec<u8>);
+pub struct Module(Vec<u8>, Option<PathRemapper>);
 
 impl Module {
     #[must_use]
     pub fn new(bytes: Vec<u8>) -> Self {
-        Self(bytes)
+        Self(bytes, None)
     }
 
     #[must_use]
     pub fn bytes(&self) -> &[u8] {
-        let Self(bytes) = self;
+        let Self(bytes, _) = self;
         bytes
     }
 
+    /// Attaches a [`PathRemapper`] applied to every `file` string this module resolves.
+    #[must_use]
+    pub fn with_path_remap(mut self, remapper: PathRemapper) -> Self {
+        self.1 = Some(remapper);
+        self
+    }
+
+    fn remap(&self, location: Location) -> Location {
+        let Self(_, remapper) = self;
+        match remapper {
+            Some(remapper) => Location {
+                file: location.file.map(|file| remapper.remap(&file)),
+                ..location
+            },
+            None => location,
+        }
+    }
+
     /// # Errors
     /// In the case parsing fails, cf. <Error> on retrieving the error info.
     pub fn from_wat(path: Option<&Path>, wat: &str) -> Result<Self, error::WatParseError> {
@@ -115,7 +213,7 @@ impl Module {
             .parse_str(path, wat)
             .map_err(|e| error::WatParseError(format!("{e:?}")))?;
 
-        Ok(Self(wat_module))
+        Ok(Self(wat_module, None))
     }
 
     /// # Errors
@@ -124,7 +222,7 @@ impl Module {
     /// # Note
     /// Cache successive calls to this method, its result does not change.
     pub fn addr2line(&self, byte_address: u64) -> Result<Location, error::Error> {
-        let Self(module) = self;
+        let Self(module, _) = self;
         let mut addr2line_modules = Addr2lineModules::parse(module)
             .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
 
@@ -140,7 +238,51 @@ impl Module {
             .map_err(|reason| error::Error::FindTextOffset1(reason.to_string()))?
             .ok_or_else(|| error::Error::FindTextOffset2(Box::from(location!())))?;
 
-        Ok(outcome.into())
+        Ok(self.remap(outcome.into()))
+    }
+
+    /// Resolves `byte_address` to its full inline call chain, innermost first.
+    ///
+    /// # Errors
+    /// In the case parsing fails, cf. <Error> on retrieving the error info.
+    ///
+    /// # Note
+    /// Cache successive calls to this method, its result does not change.
+    pub fn frames(&self, byte_address: u64) -> Result<Vec<Frame>, error::Error> {
+        let Self(module, _) = self;
+        let mut addr2line_modules = Addr2lineModules::parse(module)
+            .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
+
+        let code_section_relative = false;
+        let (ctx, text_relative_address) = addr2line_modules
+            .context(byte_address, code_section_relative)
+            .map_err(|reason| error::Error::ContextCreation1(reason.to_string()))?
+            .ok_or_else(|| error::Error::ContextCreation2(Box::from(location!())))?;
+
+        let mut frame_iter = ctx
+            .find_frames(text_relative_address)
+            .skip_all_loads()
+            .map_err(|reason| error::Error::FindFrames1(reason.to_string()))?;
+
+        let mut frames = vec![];
+        while let Some(frame) = frame_iter
+            .next()
+            .map_err(|reason| error::Error::FindFrames2(reason.to_string()))?
+        {
+            let Some(location) = frame.location else {
+                continue;
+            };
+            let function = frame
+                .function
+                .and_then(|function| function.demangle().ok().map(|name| name.into_owned()));
+
+            frames.push(Frame {
+                location: self.remap(location.into()),
+                function,
+            });
+        }
+
+        Ok(frames)
     }
 
     /// # Errors
@@ -149,7 +291,22 @@ impl Module {
     /// # Note
     /// Cache successive calls to this method, its result does not change.
     pub fn mappings(&self) -> Result<Vec<Mapping>, error::Error> {
-        let Self(module) = self;
+        self.mappings_impl(false)
+    }
+
+    /// Like [`Module::mappings`], but keeps tombstoned line-table rows instead of dropping them.
+    ///
+    /// # Errors
+    /// In the case parsing fails, cf. <Error> on retrieving the error info.
+    ///
+    /// # Note
+    /// Cache successive calls to this method, its result does not change.
+    pub fn mappings_including_tombstones(&self) -> Result<Vec<Mapping>, error::Error> {
+        self.mappings_impl(true)
+    }
+
+    fn mappings_impl(&self, keep_tombstones: bool) -> Result<Vec<Mapping>, error::Error> {
+        let Self(module, _) = self;
         let mut addr2line_modules = Addr2lineModules::parse(module)
             .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
 
@@ -171,18 +328,32 @@ impl Module {
             .map_err(|reason| error::Error::ContextCreation1(reason.to_string()))?
             .ok_or_else(|| error::Error::ContextCreation2(Box::from(location!())))?;
 
+        let function_bodies = self.function_bodies()?;
         let mut mappings = vec![];
 
         for (address, range_size, location) in ctx
             .find_location_range(text_relative_address, code_section_size.into())
             .map_err(|reason| error::Error::FindTextOffset1(reason.to_string()))?
         {
-            let location: Location = location.into();
+            if !keep_tombstones && is_tombstone_address(address, code_section_size.into()) {
+                continue;
+            }
+
+            let location: Location = self.remap(location.into());
+            // Attribute on the raw, un-fudged address: the `+ 1` below shifts a
+            // mapping that starts on a function body's last byte out of that
+            // body's range.
+            let raw_address = code_section_start_offset + address;
+            let function = function_bodies
+                .iter()
+                .find(|body| body.range.contains(&raw_address))
+                .map(|body| body.symbol.clone());
             let mapping = Mapping {
                 // FIXME: why is the `+ 1` required for the instruction offsets to match debugging info?
-                address: code_section_start_offset + address + 1,
+                address: raw_address + 1,
                 range_size,
                 location,
+                function,
             };
             mappings.push(mapping);
         }
@@ -190,6 +361,75 @@ impl Module {
         Ok(mappings)
     }
 
+    /// Lists every defined WASM function's [`FunctionSymbol`].
+    ///
+    /// # Errors
+    /// In the case parsing fails, cf. <Error> on retrieving the error info.
+    ///
+    /// # Note
+    /// Cache successive calls to this method, its result does not change.
+    pub fn symbols(&self) -> Result<Vec<FunctionSymbol>, error::Error> {
+        Ok(self
+            .function_bodies()?
+            .into_iter()
+            .map(|body| body.symbol)
+            .collect())
+    }
+
+    fn function_bodies(&self) -> Result<Vec<FunctionBody>, error::Error> {
+        let Self(module, _) = self;
+        let mut names: std::collections::HashMap<u32, String> = std::collections::HashMap::new();
+        let mut imported_function_count: u32 = 0;
+        let mut bodies = vec![];
+
+        let parser = wasmparser::Parser::default();
+        for payload in parser.parse_all(module) {
+            let payload = payload.map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
+            match payload {
+                wasmparser::Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import
+                            .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
+                        if matches!(import.ty, wasmparser::TypeRef::Func(_)) {
+                            imported_function_count += 1;
+                        }
+                    }
+                }
+                wasmparser::Payload::CodeSectionEntry(ref function_body) => {
+                    let range = function_body.range();
+                    let index = imported_function_count + bodies.len() as u32;
+                    bodies.push(FunctionBody {
+                        range: (range.start as u64)..(range.end as u64),
+                        symbol: FunctionSymbol { index, name: None },
+                    });
+                }
+                wasmparser::Payload::CustomSection(reader) if reader.name() == "name" => {
+                    let name_reader =
+                        wasmparser::NameSectionReader::new(reader.data(), reader.data_offset());
+                    for subsection in name_reader {
+                        let subsection = subsection
+                            .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
+                        if let wasmparser::Name::Function(function_names) = subsection {
+                            for naming in function_names {
+                                let naming = naming.map_err(|reason| {
+                                    error::Error::Wasmparser(reason.to_string())
+                                })?;
+                                names.insert(naming.index, naming.name.to_string());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for body in &mut bodies {
+            body.symbol.name = names.remove(&body.symbol.index);
+        }
+
+        Ok(bodies)
+    }
+
     /// Retrieves the source files that were used during compilation.
     ///
     /// # Errors
@@ -206,6 +446,113 @@ impl Module {
         Ok(files)
     }
 
+    /// Finds every code-section byte range whose [`Module::mappings`] location
+    /// matches `(file, line)`. This is built on `mappings`'s range-based
+    /// notion of line, not [`Module::addr2line`]'s point-probe one, so it is
+    /// not a strict inverse of `addr2line`.
+    ///
+    /// # Errors
+    /// In the case parsing fails, cf. <Error> on retrieving the error info.
+    ///
+    /// # Note
+    /// Cache successive calls to this method, its result does not change.
+    pub fn line2addr(&self, file: &str, line: u32) -> Result<Vec<Range<u64>>, error::Error> {
+        let ranges = self
+            .mappings()?
+            .into_iter()
+            .filter(|mapping| {
+                mapping.location.file.as_deref() == Some(file)
+                    && mapping.location.line == Some(line)
+            })
+            .map(|mapping| mapping.address..(mapping.address + mapping.range_size))
+            .collect();
+        Ok(ranges)
+    }
+
+    /// Like [`Module::mappings`], but each entry carries its full inline
+    /// call chain instead of just the line-table location, which is the
+    /// innermost frame's.
+    ///
+    /// # Errors
+    /// In the case parsing fails, cf. <Error> on retrieving the error info.
+    ///
+    /// # Note
+    /// Cache successive calls to this method, its result does not change.
+    pub fn mappings_with_frames(&self) -> Result<Vec<MappingWithFrames>, error::Error> {
+        let mappings = self.mappings()?;
+        if mappings.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let Self(module, _) = self;
+        let mut addr2line_modules = Addr2lineModules::parse(module)
+            .map_err(|reason| error::Error::Wasmparser(reason.to_string()))?;
+
+        let CodeSectionInformation {
+            start_offset: code_section_start_offset,
+            ..
+        } = match self.determine_code_section_size()? {
+            CodeSectionInformationOutcome::NoCodeSection => return Ok(vec![]),
+            CodeSectionInformationOutcome::Some(code_section_information) => {
+                code_section_information
+            }
+        };
+        let code_section_start_offset: u64 = code_section_start_offset
+            .try_into()
+            .map_err(error::Error::Cast)?;
+
+        let (ctx, text_relative_base) = addr2line_modules
+            .context(code_section_start_offset, false)
+            .map_err(|reason| error::Error::ContextCreation1(reason.to_string()))?
+            .ok_or_else(|| error::Error::ContextCreation2(Box::from(location!())))?;
+
+        // Reuse the single parsed context for every mapping instead of
+        // re-parsing the module and rebuilding DWARF state per lookup.
+        let frames_at = |text_relative_address: u64| -> Result<Vec<Frame>, error::Error> {
+            let mut frame_iter = ctx
+                .find_frames(text_relative_address)
+                .skip_all_loads()
+                .map_err(|reason| error::Error::FindFrames1(reason.to_string()))?;
+
+            let mut frames = vec![];
+            while let Some(frame) = frame_iter
+                .next()
+                .map_err(|reason| error::Error::FindFrames2(reason.to_string()))?
+            {
+                let Some(location) = frame.location else {
+                    continue;
+                };
+                let function = frame
+                    .function
+                    .and_then(|function| function.demangle().ok().map(|name| name.into_owned()));
+
+                frames.push(Frame {
+                    location: self.remap(location.into()),
+                    function,
+                });
+            }
+
+            Ok(frames)
+        };
+
+        mappings
+            .into_iter()
+            .map(|mapping| {
+                // mapping.address carries the `+ 1` fudge from mappings_impl;
+                // undo it before rederiving the context-relative address.
+                let raw_address = mapping.address - 1;
+                let text_relative_address =
+                    text_relative_base + (raw_address - code_section_start_offset);
+                Ok(MappingWithFrames {
+                    address: mapping.address,
+                    range_size: mapping.range_size,
+                    frames: frames_at(text_relative_address)?,
+                    function: mapping.function,
+                })
+            })
+            .collect()
+    }
+
     /// # Errors
     /// In the case parsing fails, cf. <Error> on retrieving the error info.
     ///
@@ -229,7 +576,7 @@ impl Module {
         mut mappings: Vec<MappingWithInstructions>,
     ) -> Result<Vec<MappingWithInstructions>, BinaryReaderError> {
         // Parse the module to find valid code offsets
-        let Self(module) = self;
+        let Self(module, _) = self;
         let parser = wasmparser::Parser::default();
 
         for payload in parser.parse_all(module) {
@@ -270,7 +617,7 @@ impl Module {
     }
 
     fn determine_code_section_size(&self) -> Result<CodeSectionInformationOutcome, error::Error> {
-        let Self(module) = self;
+        let Self(module, _) = self;
 
         // Parse the module to find valid code offsets
         let parser = wasmparser::Parser::new(0);
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use anyhow::Result;
-use wasmito_addr2line::{Module, instruction::Instruction};
+use wasmito_addr2line::{Module, PathRemapper, instruction::Instruction};
 
 const WAT: &str = r#"
 (module
@@ -91,3 +91,90 @@ fn test_from_c_works() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn mappings_with_frames_match_their_mapping() -> Result<()> {
+    let mapped_module = Module::from_wat(None, WAT)?;
+    let target = &mapped_module.mappings()?[5];
+    let mapping_with_frames = mapped_module
+        .mappings_with_frames()?
+        .into_iter()
+        .find(|mapping| mapping.address == target.address)
+        .unwrap();
+
+    assert_eq!(mapping_with_frames.frames[0].location, target.location);
+    assert_eq!(mapping_with_frames.function, target.function);
+    Ok(())
+}
+
+#[test]
+fn frames_resolve_single_frame() -> Result<()> {
+    let mapped_module = Module::from_wat(None, WAT)?;
+    let frames = mapped_module.frames(57)?;
+    assert!(!frames.is_empty());
+    assert_eq!(frames[0].location.line, Some(12));
+    assert!(frames[0].function.is_some());
+    Ok(())
+}
+
+#[test]
+fn line2addr_is_inverse_of_mappings() -> Result<()> {
+    let mapped_module = Module::from_wat(None, WAT)?;
+    let target = &mapped_module.mappings()?[5];
+    let file = target.location.file.clone().unwrap();
+    let line = target.location.line.unwrap();
+
+    let ranges = mapped_module.line2addr(&file, line)?;
+    assert!(ranges.iter().any(|range| range.contains(&target.address)));
+    Ok(())
+}
+
+#[test]
+fn symbols_attribute_mappings_to_functions() -> Result<()> {
+    let mapped_module = Module::from_wat(None, WAT)?;
+    let symbols = mapped_module.symbols()?;
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].index, 1);
+
+    let mapping = &mapped_module.mappings()?[5];
+    assert_eq!(mapping.function.as_ref().map(|symbol| symbol.index), Some(1));
+    Ok(())
+}
+
+#[test]
+fn tombstones_are_filtered_by_default() -> Result<()> {
+    let module = include_bytes!("./example_from_c.wasm");
+    let module = Module::new(module.into());
+    let filtered = module.mappings()?;
+    let raw = module.mappings_including_tombstones()?;
+    assert!(raw.len() > filtered.len());
+    Ok(())
+}
+
+#[test]
+fn path_remap_collapses_machine_specific_prefixes() -> Result<()> {
+    let module = include_bytes!("./example_from_c.wasm");
+    let remapper = PathRemapper::new([
+        ("/emsdk/emscripten".to_string(), "emsdk".to_string()),
+        (
+            "/xxxxx/xxxxxxxxxxxxxxxxxxx/xxxxxxxx/xxxxxxxxxxxxxxxx/xxxxxxxxxxxxxxxxx/path/to/source/code"
+                .to_string(),
+            "src".to_string(),
+        ),
+    ]);
+    let module = Module::new(module.into()).with_path_remap(remapper);
+    let files = module.files()?;
+
+    let expected_files = [
+        "emsdk/system/lib/libc/crt1.c",
+        "emsdk/system/lib/libc/musl/src/errno/__errno_location.c",
+        "src/lib.c",
+        "emsdk/system/lib/libc/musl/src/exit/_Exit.c",
+        "emsdk/system/lib/libc/musl/src/exit/exit.c",
+    ]
+    .iter()
+    .map(std::string::ToString::to_string)
+    .collect::<HashSet<_>>();
+    assert_eq!(files, expected_files);
+    Ok(())
+}